@@ -0,0 +1,221 @@
+//! AVM2 event dispatch.
+//!
+//! Implements the DOM3-style event flow (capture, at-target, bubble) that
+//! backs `EventDispatcher.dispatchEvent` and the other dispatch-adjacent
+//! methods on `flash.events.EventDispatcher`.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::globals::flash::events::eventdispatcher::dispatch_list;
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::Error;
+use gc_arena::Collect;
+
+/// Find the logical parent of a dispatch target, if any.
+///
+/// For display objects this walks up the display list; other kinds of
+/// `EventDispatcher` (which have no notion of a parent) simply have none.
+pub fn parent_of<'gc>(target: Object<'gc>) -> Option<Object<'gc>> {
+    target
+        .as_display_object()
+        .and_then(|dobj| dobj.parent())
+        .and_then(|parent| parent.object())
+}
+
+/// Dispatch `event` to `target`, running the full capture/at-target/bubble
+/// event flow and resetting the event's `target`, `currentTarget`, and
+/// `eventPhase` as it moves between passes.
+///
+/// Returns an error if `event` is already being dispatched somewhere else
+/// on the call stack: the reference player does not allow a single
+/// `Event` instance to be in flight twice at once, since its phase and
+/// target fields are shared mutable state that an inner dispatch would
+/// otherwise clobber out from under the outer one.
+pub fn dispatch_event<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    target: Object<'gc>,
+    event: Object<'gc>,
+) -> Result<bool, Error<'gc>> {
+    let event_handle = event
+        .as_event()
+        .ok_or("Dispatched Events must be subclasses of Event.")?;
+
+    if event_handle.is_dispatching() {
+        return Err(Error::from(
+            "Error #2065: Redispatching an event that is currently being dispatched is not allowed.",
+        ));
+    }
+
+    event_handle.set_dispatching(activation.context.gc_context, true);
+    event_handle.set_target(activation.context.gc_context, Some(target));
+
+    let result = dispatch_event_to_phases(activation, target, event);
+
+    // Always clear the in-flight flag, even on error, so a listener that
+    // throws doesn't permanently lock the event out of being redispatched.
+    event_handle.set_dispatching(activation.context.gc_context, false);
+    event_handle.set_current_target(activation.context.gc_context, None);
+
+    result
+}
+
+/// Run `event`'s capture, at-target, and bubble passes against `target`,
+/// resetting `currentTarget`/`eventPhase` at each transition.
+fn dispatch_event_to_phases<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    target: Object<'gc>,
+    event: Object<'gc>,
+) -> Result<bool, Error<'gc>> {
+    let event_handle = event.as_event().expect("validated by caller");
+
+    // Collect the ancestor chain once: capturing walks it root-to-target,
+    // bubbling walks it target-to-root.
+    let mut ancestors = Vec::new();
+    let mut next = parent_of(target);
+    while let Some(ancestor) = next {
+        next = parent_of(ancestor);
+        ancestors.push(ancestor);
+    }
+
+    for ancestor in ancestors.iter().rev() {
+        event_handle.set_phase(activation.context.gc_context, EventPhase::Capturing);
+        event_handle.set_current_target(activation.context.gc_context, Some(*ancestor));
+
+        dispatch_to_listeners(activation, *ancestor, event, EventPhase::Capturing)?;
+
+        if event_handle.is_propagation_stopped() {
+            return Ok(!event_handle.is_cancelled());
+        }
+    }
+
+    event_handle.set_phase(activation.context.gc_context, EventPhase::AtTarget);
+    event_handle.set_current_target(activation.context.gc_context, Some(target));
+    dispatch_to_listeners(activation, target, event, EventPhase::AtTarget)?;
+
+    if event_handle.is_propagation_stopped() || !event_handle.is_bubbling() {
+        return Ok(!event_handle.is_cancelled());
+    }
+
+    for ancestor in ancestors.iter() {
+        event_handle.set_phase(activation.context.gc_context, EventPhase::Bubbling);
+        event_handle.set_current_target(activation.context.gc_context, Some(*ancestor));
+
+        dispatch_to_listeners(activation, *ancestor, event, EventPhase::Bubbling)?;
+
+        if event_handle.is_propagation_stopped() {
+            break;
+        }
+    }
+
+    Ok(!event_handle.is_cancelled())
+}
+
+/// Invoke every listener registered on `current_target` for `event`'s
+/// type, in priority order, stopping early if a listener calls
+/// `stopImmediatePropagation`.
+fn dispatch_to_listeners<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    current_target: Object<'gc>,
+    event: Object<'gc>,
+    phase: EventPhase,
+) -> Result<(), Error<'gc>> {
+    let event_handle = event.as_event().expect("validated by caller");
+    let event_type = event_handle.event_type();
+
+    let handlers = dispatch_list(activation, current_target)
+        .ok()
+        .and_then(|list| list.as_dispatch_mut(activation.context.gc_context))
+        .map(|mut list| list.get_event_list(activation.context.gc_context, event_type))
+        .unwrap_or_default();
+
+    for (_priority, callback, handler_use_capture) in handlers {
+        if !listener_fires(phase, handler_use_capture) {
+            continue;
+        }
+
+        callback.call(Some(current_target), &[event.into()], activation)?;
+
+        if event_handle.is_propagation_stopped_immediately() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a listener registered with `useCapture == handler_use_capture`
+/// should fire during `phase`.
+///
+/// At-target listeners fire exactly once regardless of `useCapture` - per
+/// DOM3/AS3, `useCapture` only distinguishes *ancestor* listeners (it picks
+/// whether they see the event on the way down or the way back up); a
+/// listener registered directly on the target itself is neither, so it
+/// always fires at that single at-target pass.
+fn listener_fires(phase: EventPhase, handler_use_capture: bool) -> bool {
+    match phase {
+        EventPhase::AtTarget => true,
+        EventPhase::Capturing => handler_use_capture,
+        EventPhase::Bubbling => !handler_use_capture,
+    }
+}
+
+/// Mirrors `flash.events.EventPhase`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Collect)]
+#[collect(require_static)]
+pub enum EventPhase {
+    Capturing = 1,
+    AtTarget = 2,
+    Bubbling = 3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm2::object::StageObject;
+    use crate::display_object::video::DEFAULT_VIDEO_SIZE;
+    use crate::display_object::video::Video;
+
+    #[test]
+    fn parent_of_resolves_through_the_display_list() {
+        gc_arena::rootless_arena(|mc| {
+            let (w, h) = DEFAULT_VIDEO_SIZE;
+            let parent_video = Video::new(mc, w, h);
+            let parent_object: Object = StageObject::for_display_object(mc, parent_video.into()).into();
+
+            let child_video = Video::new(mc, w, h);
+            let child_object: Object = StageObject::for_display_object(mc, child_video.into()).into();
+            child_video.set_parent(mc, Some(parent_video.into()));
+
+            let found = parent_of(child_object).expect("child has a parent");
+            assert!(Object::ptr_eq(found, parent_object));
+        });
+    }
+
+    #[test]
+    fn parent_of_is_none_without_a_parent() {
+        gc_arena::rootless_arena(|mc| {
+            let (w, h) = DEFAULT_VIDEO_SIZE;
+            let video = Video::new(mc, w, h);
+            let object: Object = StageObject::for_display_object(mc, video.into()).into();
+
+            assert!(parent_of(object).is_none());
+        });
+    }
+
+    #[test]
+    fn at_target_listeners_fire_regardless_of_use_capture() {
+        assert!(listener_fires(EventPhase::AtTarget, true));
+        assert!(listener_fires(EventPhase::AtTarget, false));
+    }
+
+    #[test]
+    fn capturing_pass_only_fires_use_capture_listeners() {
+        assert!(listener_fires(EventPhase::Capturing, true));
+        assert!(!listener_fires(EventPhase::Capturing, false));
+    }
+
+    #[test]
+    fn bubbling_pass_only_fires_non_use_capture_listeners() {
+        assert!(!listener_fires(EventPhase::Bubbling, true));
+        assert!(listener_fires(EventPhase::Bubbling, false));
+    }
+}
@@ -39,7 +39,7 @@ pub fn instance_init<'gc>(
 }
 
 /// Get an object's dispatch list, lazily initializing it if necessary.
-fn dispatch_list<'gc>(
+pub(crate) fn dispatch_list<'gc>(
     activation: &mut Activation<'_, 'gc>,
     mut this: Object<'gc>,
 ) -> Result<Object<'gc>, Error<'gc>> {
@@ -89,12 +89,23 @@ pub fn add_event_listener<'gc>(
             .cloned()
             .unwrap_or(Value::Integer(0))
             .coerce_to_i32(activation)?;
+        let use_weak = args
+            .get(4)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
 
-        //TODO: If we ever get weak GC references, we should respect `useWeakReference`.
         dispatch_list
             .as_dispatch_mut(activation.context.gc_context)
             .ok_or_else(|| Error::from("Internal properties should have what I put in them"))?
-            .add_event_listener(event_type, priority, listener, use_capture);
+            .add_event_listener(
+                activation.context.gc_context,
+                event_type,
+                priority,
+                listener,
+                use_capture,
+                use_weak,
+            );
 
         Avm2::register_broadcast_listener(&mut activation.context, this, event_type);
     }
@@ -129,7 +140,12 @@ pub fn remove_event_listener<'gc>(
         dispatch_list
             .as_dispatch_mut(activation.context.gc_context)
             .ok_or_else(|| Error::from("Internal properties should have what I put in them"))?
-            .remove_event_listener(event_type, listener, use_capture);
+            .remove_event_listener(
+                activation.context.gc_context,
+                event_type,
+                listener,
+                use_capture,
+            );
     }
 
     Ok(Value::Undefined)
@@ -152,7 +168,7 @@ pub fn has_event_listener<'gc>(
         return Ok(dispatch_list
             .as_dispatch_mut(activation.context.gc_context)
             .ok_or_else(|| Error::from("Internal properties should have what I put in them"))?
-            .has_event_listener(event_type)
+            .has_event_listener(activation.context.gc_context, event_type)
             .into());
     }
 
@@ -176,7 +192,7 @@ pub fn will_trigger<'gc>(
         if dispatch_list
             .as_dispatch_mut(activation.context.gc_context)
             .ok_or_else(|| Error::from("Internal properties should have what I put in them"))?
-            .has_event_listener(event_type)
+            .has_event_listener(activation.context.gc_context, event_type)
         {
             return Ok(true.into());
         }
@@ -198,6 +214,10 @@ pub fn will_trigger<'gc>(
 }
 
 /// Implements `EventDispatcher.dispatchEvent`.
+///
+/// The actual capture/at-target/bubble traversal, along with the
+/// in-flight redispatch check, lives in `avm2::events::dispatch_event`;
+/// this just validates the argument and forwards to it.
 pub fn dispatch_event<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
@@ -2,23 +2,58 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
-use crate::avm2::method::Method;
-use crate::avm2::object::Object;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::object::{ClassObject, Object, StageObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::QName;
+use crate::display_object::video::{Video, DEFAULT_VIDEO_SIZE};
 use gc_arena::GcCell;
 
+/// Allocates the AVM2 object for a `Video` instance.
+///
+/// `Video` is backed by a real display-list node (a `DisplayObject::
+/// Video`), so - like every other `DisplayObject`-derived class - its
+/// instances are allocated with this dedicated allocator rather than the
+/// default script-object allocator, ensuring the backing `Video` exists
+/// before `instance_init` (or any getter) ever runs.
+pub fn video_allocator<'gc>(
+    _class: ClassObject<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Object<'gc>, Error<'gc>> {
+    let (default_width, default_height) = DEFAULT_VIDEO_SIZE;
+    let video = Video::new(activation.context.gc_context, default_width, default_height);
+    let stage_object = StageObject::for_display_object(activation.context.gc_context, video.into());
+
+    Ok(stage_object.into())
+}
+
 /// Implements `flash.media.Video`'s instance constructor.
 pub fn instance_init<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         activation.super_init(this, &[])?;
+
+        let (default_width, default_height) = DEFAULT_VIDEO_SIZE;
+        let width = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(|| default_width.into())
+            .coerce_to_u32(activation)?;
+        let height = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| default_height.into())
+            .coerce_to_u32(activation)?;
+
+        if let Some(video) = this.as_display_object().and_then(|dobj| dobj.as_video()) {
+            video.set_dimensions(activation.context.gc_context, width, height);
+        }
     }
 
     Ok(Value::Undefined)
@@ -33,6 +68,177 @@ pub fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Video.videoWidth`'s getter.
+///
+/// The reference player reports the attached media's actual decoded
+/// frame width here, which can differ from the constructor's bounding
+/// box once a `NetStream`/`Camera` is attached. `VideoSource` doesn't
+/// track a decoded frame size yet, so this is simplified to the box
+/// width until frame decoding is wired up.
+pub fn video_width<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        return Ok(video.width().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.videoHeight`'s getter.
+///
+/// See `video_width` above: this is simplified to the box height until
+/// frame decoding reports an actual decoded size.
+pub fn video_height<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        return Ok(video.height().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.smoothing`'s getter.
+pub fn smoothing<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        return Ok(video.smoothing().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.smoothing`'s setter.
+pub fn set_smoothing<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+
+        video.set_smoothing(activation.context.gc_context, value);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.deblocking`'s getter.
+pub fn deblocking<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        return Ok(video.deblocking().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.deblocking`'s setter.
+pub fn set_deblocking<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Integer(0))
+            .coerce_to_i32(activation)?;
+
+        video.set_deblocking(activation.context.gc_context, value);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.attachNetStream`.
+pub fn attach_net_stream<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Object(ns) => video.attach_net_stream(activation.context.gc_context, ns),
+            _ => video.clear(activation.context.gc_context),
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.attachCamera`.
+pub fn attach_camera<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Object(cam) => video.attach_camera(activation.context.gc_context, cam),
+            _ => video.clear(activation.context.gc_context),
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Video.clear`.
+pub fn clear<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        video.clear(activation.context.gc_context);
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `Video`'s class.
 pub fn create_class<'gc>(activation: &mut Activation<'_, 'gc>) -> GcCell<'gc, Class<'gc>> {
     let mc = activation.context.gc_context;
@@ -50,6 +256,34 @@ pub fn create_class<'gc>(activation: &mut Activation<'_, 'gc>) -> GcCell<'gc, Cl
     let mut write = class.write(mc);
 
     write.set_attributes(ClassAttributes::SEALED);
+    write.set_instance_allocator(video_allocator);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("attachNetStream", attach_net_stream),
+        ("attachCamera", attach_camera),
+        ("clear", clear),
+    ];
+    write.define_builtin_instance_methods(
+        mc,
+        activation.avm2().public_namespace,
+        PUBLIC_INSTANCE_METHODS,
+    );
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("videoWidth", Some(video_width), None),
+        ("videoHeight", Some(video_height), None),
+        ("smoothing", Some(smoothing), Some(set_smoothing)),
+        ("deblocking", Some(deblocking), Some(set_deblocking)),
+    ];
+    write.define_builtin_instance_properties(
+        mc,
+        activation.avm2().public_namespace,
+        PUBLIC_INSTANCE_PROPERTIES,
+    );
 
     class
 }
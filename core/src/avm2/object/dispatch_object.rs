@@ -0,0 +1,303 @@
+//! Storage for the list of event listeners attached to an
+//! `EventDispatcher`-backed object.
+
+use crate::avm2::object::{Object, ObjectPtr, TObject, WeakObject};
+use crate::string::AvmString;
+use core::fmt;
+use gc_arena::{Collect, GcCell, GcWeakCell, MutationContext};
+
+/// A `DispatchObject` is the internal, non-scriptable object stashed away
+/// in an `EventDispatcher`'s `dispatch_list` slot. It just wraps a
+/// `DispatchList` in a `GcCell` so it can be stored as an ordinary object
+/// property.
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct DispatchObject<'gc>(GcCell<'gc, DispatchList<'gc>>);
+
+impl<'gc> DispatchObject<'gc> {
+    /// Construct an empty event dispatch list.
+    pub fn empty_list(mc: MutationContext<'gc, '_>) -> Object<'gc> {
+        DispatchObject(GcCell::allocate(mc, DispatchList::new())).into()
+    }
+
+    /// Register an event listener for a given event type.
+    ///
+    /// If `use_weak` is `true`, the listener is held with a weak GC
+    /// reference, obtained from the handler's own `Object::downgrade`:
+    /// it keeps receiving events for as long as something else keeps it
+    /// alive, but the dispatch list itself won't prevent it from being
+    /// collected, matching `useWeakReference` on the reference player.
+    pub fn add_event_listener(
+        &mut self,
+        mc: MutationContext<'gc, '_>,
+        event: AvmString<'gc>,
+        priority: i32,
+        handler: Object<'gc>,
+        use_capture: bool,
+        use_weak: bool,
+    ) {
+        self.0
+            .write(mc)
+            .add_event_listener(mc, event, priority, handler, use_capture, use_weak);
+    }
+
+    pub fn remove_event_listener(
+        &mut self,
+        mc: MutationContext<'gc, '_>,
+        event: AvmString<'gc>,
+        handler: Object<'gc>,
+        use_capture: bool,
+    ) {
+        self.0
+            .write(mc)
+            .remove_event_listener(mc, event, handler, use_capture);
+    }
+
+    /// Determine if there are any event listeners registered for a given
+    /// event type, pruning any weak listeners that have since been
+    /// collected as we go.
+    pub fn has_event_listener(
+        &mut self,
+        mc: MutationContext<'gc, '_>,
+        event: AvmString<'gc>,
+    ) -> bool {
+        self.0.write(mc).has_event_listener(mc, event)
+    }
+
+    /// Collect the live handlers registered for a given event, in
+    /// dispatch order, as `(priority, callback, use_capture)`.
+    pub fn get_event_list(
+        &mut self,
+        mc: MutationContext<'gc, '_>,
+        event: AvmString<'gc>,
+    ) -> Vec<(i32, Object<'gc>, bool)> {
+        self.0.write(mc).get_event_list(mc, event)
+    }
+
+    pub fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    pub fn downgrade(&self) -> WeakDispatchObject<'gc> {
+        WeakDispatchObject(GcCell::downgrade(self.0))
+    }
+}
+
+impl<'gc> TObject<'gc> for DispatchObject<'gc> {
+    fn as_ptr(&self) -> *const ObjectPtr {
+        DispatchObject::as_ptr(self)
+    }
+}
+
+impl<'gc> DispatchObject<'gc> {
+    pub fn as_dispatch_mut(&self, _mc: MutationContext<'gc, '_>) -> Option<DispatchObject<'gc>> {
+        Some(*self)
+    }
+}
+
+impl fmt::Debug for DispatchObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DispatchObject").finish()
+    }
+}
+
+/// A weak handle to a `DispatchObject`, obtained via
+/// `DispatchObject::downgrade`.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct WeakDispatchObject<'gc>(GcWeakCell<'gc, DispatchList<'gc>>);
+
+impl<'gc> WeakDispatchObject<'gc> {
+    pub fn upgrade(&self, mc: MutationContext<'gc, '_>) -> Option<DispatchObject<'gc>> {
+        self.0.upgrade(mc).map(DispatchObject)
+    }
+}
+
+/// A single registered listener, held either strongly or weakly depending
+/// on whether it was registered with `useWeakReference`.
+///
+/// Weak entries are pruned lazily, when a listener is added, when the list
+/// is asked whether it has a listener, and when it is collected for
+/// dispatch, rather than proactively, since there's no collection callback
+/// to hook a prune into.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+enum Listener<'gc> {
+    Strong(Object<'gc>),
+    Weak(WeakObject<'gc>),
+}
+
+impl<'gc> Listener<'gc> {
+    fn new(handler: Object<'gc>, use_weak: bool) -> Self {
+        if use_weak {
+            Listener::Weak(handler.downgrade())
+        } else {
+            Listener::Strong(handler)
+        }
+    }
+
+    /// Resolve this listener back to a callable object, if it (or its weak
+    /// target) is still alive.
+    fn upgrade(&self, mc: MutationContext<'gc, '_>) -> Option<Object<'gc>> {
+        match self {
+            Listener::Strong(o) => Some(*o),
+            Listener::Weak(weak) => weak.upgrade(mc),
+        }
+    }
+
+    fn is_same_listener(
+        &self,
+        mc: MutationContext<'gc, '_>,
+        other: Object<'gc>,
+        use_capture: bool,
+        expect_capture: bool,
+    ) -> bool {
+        use_capture == expect_capture
+            && self
+                .upgrade(mc)
+                .map(|o| Object::ptr_eq(o, other))
+                .unwrap_or(false)
+    }
+}
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+struct EventHandler<'gc> {
+    event_type: AvmString<'gc>,
+    priority: i32,
+    listener: Listener<'gc>,
+    use_capture: bool,
+}
+
+/// An event handler list, sorted by priority (highest first) with
+/// registration order as a tiebreaker, per the AVM2 spec.
+#[derive(Clone, Collect, Default)]
+#[collect(no_drop)]
+pub struct DispatchList<'gc>(Vec<EventHandler<'gc>>);
+
+impl<'gc> DispatchList<'gc> {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn add_event_listener(
+        &mut self,
+        mc: MutationContext<'gc, '_>,
+        event: AvmString<'gc>,
+        priority: i32,
+        handler: Object<'gc>,
+        use_capture: bool,
+        use_weak: bool,
+    ) {
+        self.prune_dead_listeners(mc);
+
+        if self.0.iter().any(|eh| {
+            eh.event_type == event
+                && eh
+                    .listener
+                    .is_same_listener(mc, handler, eh.use_capture, use_capture)
+        }) {
+            return;
+        }
+
+        let position = self
+            .0
+            .iter()
+            .position(|eh| eh.event_type == event && eh.priority < priority)
+            .unwrap_or(self.0.len());
+
+        self.0.insert(
+            position,
+            EventHandler {
+                event_type: event,
+                priority,
+                listener: Listener::new(handler, use_weak),
+                use_capture,
+            },
+        );
+    }
+
+    pub fn remove_event_listener(
+        &mut self,
+        mc: MutationContext<'gc, '_>,
+        event: AvmString<'gc>,
+        handler: Object<'gc>,
+        use_capture: bool,
+    ) {
+        self.0.retain(|eh| {
+            !(eh.event_type == event
+                && eh
+                    .listener
+                    .is_same_listener(mc, handler, eh.use_capture, use_capture))
+        });
+    }
+
+    /// Prune any weak listeners that have been collected, then report
+    /// whether any listeners remain for the given event type.
+    pub fn has_event_listener(&mut self, mc: MutationContext<'gc, '_>, event: AvmString<'gc>) -> bool {
+        self.prune_dead_listeners(mc);
+
+        self.0.iter().any(|eh| eh.event_type == event)
+    }
+
+    /// Collect the live handlers for a given event, in dispatch order, as
+    /// `(priority, callback, use_capture)`, pruning any dead weak
+    /// listeners found along the way.
+    pub fn get_event_list(
+        &mut self,
+        mc: MutationContext<'gc, '_>,
+        event: AvmString<'gc>,
+    ) -> Vec<(i32, Object<'gc>, bool)> {
+        self.prune_dead_listeners(mc);
+
+        self.0
+            .iter()
+            .filter(|eh| eh.event_type == event)
+            .filter_map(|eh| eh.listener.upgrade(mc).map(|o| (eh.priority, o, eh.use_capture)))
+            .collect()
+    }
+
+    /// Drop any entries whose weak listener has since been garbage
+    /// collected.
+    fn prune_dead_listeners(&mut self, mc: MutationContext<'gc, '_>) {
+        self.0.retain(|eh| eh.listener.upgrade(mc).is_some());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm2::object::ScriptObject;
+
+    #[test]
+    fn weak_listener_is_upgradeable_while_referenced_elsewhere() {
+        gc_arena::rootless_arena(|mc| {
+            let mut list = DispatchList::new();
+            let event = AvmString::from("click");
+            let handler: Object = ScriptObject::new(mc).into();
+
+            list.add_event_listener(mc, event, 0, handler, false, true);
+
+            let fired: Vec<_> = list
+                .get_event_list(mc, event)
+                .into_iter()
+                .map(|(_, callback, _)| Object::ptr_eq(callback, handler))
+                .collect();
+            assert_eq!(fired, vec![true]);
+        });
+    }
+
+    #[test]
+    fn duplicate_registration_with_same_use_capture_is_ignored() {
+        gc_arena::rootless_arena(|mc| {
+            let mut list = DispatchList::new();
+            let event = AvmString::from("click");
+            let handler: Object = ScriptObject::new(mc).into();
+
+            list.add_event_listener(mc, event, 0, handler, false, false);
+            list.add_event_listener(mc, event, 0, handler, false, false);
+
+            assert_eq!(list.get_event_list(mc, event).len(), 1);
+        });
+    }
+}
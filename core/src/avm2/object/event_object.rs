@@ -0,0 +1,210 @@
+//! Storage for the mutable dispatch-time state of a `flash.events.Event`
+//! instance: its target/currentTarget/eventPhase, and the propagation and
+//! cancellation flags set by `stopPropagation`/`stopImmediatePropagation`/
+//! `preventDefault`. (The rest of `Event` - its AS3-visible properties and
+//! methods - lives outside this tree; this only backs the native state
+//! `avm2::events`'s dispatch machinery reads and writes.)
+
+use crate::avm2::events::EventPhase;
+use crate::avm2::object::{Object, ObjectPtr, TObject};
+use crate::string::AvmString;
+use core::fmt;
+use gc_arena::{Collect, GcCell, GcWeakCell, MutationContext};
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+struct EventData<'gc> {
+    event_type: AvmString<'gc>,
+    bubbles: bool,
+    cancelable: bool,
+    phase: EventPhase,
+    target: Option<Object<'gc>>,
+    current_target: Option<Object<'gc>>,
+    dispatching: bool,
+    propagation_stopped: bool,
+    propagation_stopped_immediately: bool,
+    cancelled: bool,
+}
+
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct EventObject<'gc>(GcCell<'gc, EventData<'gc>>);
+
+impl<'gc> EventObject<'gc> {
+    pub fn new(
+        mc: MutationContext<'gc, '_>,
+        event_type: AvmString<'gc>,
+        bubbles: bool,
+        cancelable: bool,
+    ) -> Self {
+        Self(GcCell::allocate(
+            mc,
+            EventData {
+                event_type,
+                bubbles,
+                cancelable,
+                phase: EventPhase::AtTarget,
+                target: None,
+                current_target: None,
+                dispatching: false,
+                propagation_stopped: false,
+                propagation_stopped_immediately: false,
+                cancelled: false,
+            },
+        ))
+    }
+
+    pub fn event_type(self) -> AvmString<'gc> {
+        self.0.read().event_type
+    }
+
+    pub fn is_bubbling(self) -> bool {
+        self.0.read().bubbles
+    }
+
+    pub fn is_cancelable(self) -> bool {
+        self.0.read().cancelable
+    }
+
+    pub fn is_cancelled(self) -> bool {
+        self.0.read().cancelled
+    }
+
+    pub fn is_dispatching(self) -> bool {
+        self.0.read().dispatching
+    }
+
+    /// Mark this event as currently being dispatched (or not). Starting a
+    /// new dispatch (`value == true`) also resets the propagation and
+    /// cancellation flags left over from whatever dispatch last used this
+    /// `Event`.
+    pub fn set_dispatching(self, mc: MutationContext<'gc, '_>, value: bool) {
+        let mut write = self.0.write(mc);
+        write.dispatching = value;
+
+        if value {
+            write.propagation_stopped = false;
+            write.propagation_stopped_immediately = false;
+            write.cancelled = false;
+        }
+    }
+
+    pub fn target(self) -> Option<Object<'gc>> {
+        self.0.read().target
+    }
+
+    pub fn set_target(self, mc: MutationContext<'gc, '_>, target: Option<Object<'gc>>) {
+        self.0.write(mc).target = target;
+    }
+
+    pub fn current_target(self) -> Option<Object<'gc>> {
+        self.0.read().current_target
+    }
+
+    pub fn set_current_target(self, mc: MutationContext<'gc, '_>, current_target: Option<Object<'gc>>) {
+        self.0.write(mc).current_target = current_target;
+    }
+
+    pub fn phase(self) -> EventPhase {
+        self.0.read().phase
+    }
+
+    pub fn set_phase(self, mc: MutationContext<'gc, '_>, phase: EventPhase) {
+        self.0.write(mc).phase = phase;
+    }
+
+    pub fn is_propagation_stopped(self) -> bool {
+        self.0.read().propagation_stopped
+    }
+
+    pub fn is_propagation_stopped_immediately(self) -> bool {
+        self.0.read().propagation_stopped_immediately
+    }
+
+    /// Implements `Event.stopPropagation`.
+    pub fn stop_propagation(self, mc: MutationContext<'gc, '_>) {
+        self.0.write(mc).propagation_stopped = true;
+    }
+
+    /// Implements `Event.stopImmediatePropagation`.
+    pub fn stop_immediate_propagation(self, mc: MutationContext<'gc, '_>) {
+        let mut write = self.0.write(mc);
+        write.propagation_stopped = true;
+        write.propagation_stopped_immediately = true;
+    }
+
+    /// Implements `Event.preventDefault`. A no-op if this event isn't
+    /// cancelable, matching the reference player.
+    pub fn prevent_default(self, mc: MutationContext<'gc, '_>) {
+        let mut write = self.0.write(mc);
+        if write.cancelable {
+            write.cancelled = true;
+        }
+    }
+
+    pub fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    pub fn downgrade(&self) -> WeakEventObject<'gc> {
+        WeakEventObject(GcCell::downgrade(self.0))
+    }
+}
+
+impl<'gc> TObject<'gc> for EventObject<'gc> {
+    fn as_ptr(&self) -> *const ObjectPtr {
+        EventObject::as_ptr(self)
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventObject").finish()
+    }
+}
+
+/// A weak handle to an `EventObject`, obtained via `EventObject::downgrade`.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct WeakEventObject<'gc>(GcWeakCell<'gc, EventData<'gc>>);
+
+impl<'gc> WeakEventObject<'gc> {
+    pub fn upgrade(&self, mc: MutationContext<'gc, '_>) -> Option<EventObject<'gc>> {
+        self.0.upgrade(mc).map(EventObject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starting_a_dispatch_clears_stale_propagation_state() {
+        gc_arena::rootless_arena(|mc| {
+            let event = EventObject::new(mc, AvmString::from("x"), false, true);
+
+            event.set_dispatching(mc, true);
+            event.stop_immediate_propagation(mc);
+            event.prevent_default(mc);
+            event.set_dispatching(mc, false);
+
+            // A fresh dispatch of the same `Event` shouldn't see the
+            // previous dispatch's stopPropagation/preventDefault.
+            event.set_dispatching(mc, true);
+            assert!(!event.is_propagation_stopped());
+            assert!(!event.is_propagation_stopped_immediately());
+            assert!(!event.is_cancelled());
+        });
+    }
+
+    #[test]
+    fn prevent_default_is_a_no_op_when_not_cancelable() {
+        gc_arena::rootless_arena(|mc| {
+            let event = EventObject::new(mc, AvmString::from("x"), false, false);
+
+            event.prevent_default(mc);
+
+            assert!(!event.is_cancelled());
+        });
+    }
+}
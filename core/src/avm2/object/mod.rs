@@ -0,0 +1,150 @@
+//! AVM2 object representations.
+//!
+//! This module only models the slice of the object system touched by the
+//! `EventDispatcher` and `Video` globals implemented in this tree
+//! (dispatch lists, generic script objects, display-object-backed
+//! objects, and the dispatch-time state of `Event`). The rest of the
+//! real object system - `FunctionObject`, the various other native
+//! object kinds, and so on - lives outside it.
+
+mod dispatch_object;
+mod event_object;
+mod script_object;
+mod stage_object;
+
+pub use dispatch_object::DispatchObject;
+pub use event_object::EventObject;
+pub use script_object::ScriptObject;
+pub use stage_object::StageObject;
+
+use crate::display_object::DisplayObject;
+use gc_arena::{Collect, MutationContext};
+use std::fmt::Debug;
+
+/// An opaque, uninhabited marker type used purely to get a comparable,
+/// `'static`-free raw pointer out of whatever concrete `GcCell` backs an
+/// object, for identity comparisons (`Object::ptr_eq`). It is never itself
+/// allocated, and has no bearing on any object's actual GC-tracked
+/// payload - it must not be used as the target of a `GcWeakCell`.
+pub enum ObjectPtr {}
+
+/// A reference to an AVM2 object.
+///
+/// Each variant wraps its own concrete `GcCell`-backed data; there is no
+/// single underlying allocation shared between them, which is why
+/// identity (`as_ptr`) and weak references (`downgrade`) both have to be
+/// implemented per-variant rather than generically.
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub enum Object<'gc> {
+    Dispatch(DispatchObject<'gc>),
+    Event(EventObject<'gc>),
+    Script(ScriptObject<'gc>),
+    Stage(StageObject<'gc>),
+}
+
+impl<'gc> From<DispatchObject<'gc>> for Object<'gc> {
+    fn from(o: DispatchObject<'gc>) -> Self {
+        Object::Dispatch(o)
+    }
+}
+
+impl<'gc> From<EventObject<'gc>> for Object<'gc> {
+    fn from(o: EventObject<'gc>) -> Self {
+        Object::Event(o)
+    }
+}
+
+impl<'gc> From<ScriptObject<'gc>> for Object<'gc> {
+    fn from(o: ScriptObject<'gc>) -> Self {
+        Object::Script(o)
+    }
+}
+
+impl<'gc> From<StageObject<'gc>> for Object<'gc> {
+    fn from(o: StageObject<'gc>) -> Self {
+        Object::Stage(o)
+    }
+}
+
+impl<'gc> Object<'gc> {
+    pub fn ptr_eq(a: Object<'gc>, b: Object<'gc>) -> bool {
+        a.as_ptr() == b.as_ptr()
+    }
+
+    /// Get a weak handle to this object, for use by things like
+    /// `EventDispatcher.addEventListener`'s `useWeakReference`.
+    pub fn downgrade(&self) -> WeakObject<'gc> {
+        match self {
+            Object::Dispatch(o) => WeakObject::Dispatch(o.downgrade()),
+            Object::Event(o) => WeakObject::Event(o.downgrade()),
+            Object::Script(o) => WeakObject::Script(o.downgrade()),
+            Object::Stage(o) => WeakObject::Stage(o.downgrade()),
+        }
+    }
+
+    pub fn as_dispatch_mut(&self, mc: MutationContext<'gc, '_>) -> Option<DispatchObject<'gc>> {
+        match self {
+            Object::Dispatch(o) => o.as_dispatch_mut(mc),
+            _ => None,
+        }
+    }
+
+    /// Get the display object this AVM2 object is backed by, if it
+    /// represents one (i.e. if its class extends `flash.display.
+    /// DisplayObject`).
+    pub fn as_display_object(&self) -> Option<DisplayObject<'gc>> {
+        match self {
+            Object::Stage(o) => Some(o.display_object()),
+            _ => None,
+        }
+    }
+
+    /// Get this object's `Event` dispatch-time state, if it's one (i.e.
+    /// if its class extends `flash.events.Event`).
+    pub fn as_event(&self) -> Option<EventObject<'gc>> {
+        match self {
+            Object::Event(o) => Some(*o),
+            _ => None,
+        }
+    }
+
+    fn as_ptr(&self) -> *const ObjectPtr {
+        match self {
+            Object::Dispatch(o) => o.as_ptr(),
+            Object::Event(o) => o.as_ptr(),
+            Object::Script(o) => o.as_ptr(),
+            Object::Stage(o) => o.as_ptr(),
+        }
+    }
+}
+
+/// A weak handle to an `Object`, tagged by which concrete kind of object
+/// was downgraded so that upgrading doesn't need any outside information
+/// about what it used to point to.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub enum WeakObject<'gc> {
+    Dispatch(dispatch_object::WeakDispatchObject<'gc>),
+    Event(event_object::WeakEventObject<'gc>),
+    Script(script_object::WeakScriptObject<'gc>),
+    Stage(stage_object::WeakStageObject<'gc>),
+}
+
+impl<'gc> WeakObject<'gc> {
+    /// Resolve this handle back to a live `Object`, or `None` if it's
+    /// been collected.
+    pub fn upgrade(&self, mc: MutationContext<'gc, '_>) -> Option<Object<'gc>> {
+        match self {
+            WeakObject::Dispatch(w) => w.upgrade(mc).map(Object::Dispatch),
+            WeakObject::Event(w) => w.upgrade(mc).map(Object::Event),
+            WeakObject::Script(w) => w.upgrade(mc).map(Object::Script),
+            WeakObject::Stage(w) => w.upgrade(mc).map(Object::Stage),
+        }
+    }
+}
+
+/// Common behavior every concrete AVM2 object kind implements.
+pub trait TObject<'gc>: 'gc + Collect + Copy + Debug + Into<Object<'gc>> {
+    fn as_ptr(&self) -> *const ObjectPtr;
+}
@@ -0,0 +1,58 @@
+//! Generic AVM2 object, used for plain objects and callables (e.g. the
+//! listener functions registered with `EventDispatcher.addEventListener`).
+
+use crate::avm2::object::{ObjectPtr, TObject};
+use gc_arena::{Collect, GcCell, GcWeakCell, MutationContext};
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct ScriptObjectData<'gc> {
+    _marker: std::marker::PhantomData<&'gc ()>,
+}
+
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct ScriptObject<'gc>(pub GcCell<'gc, ScriptObjectData<'gc>>);
+
+impl<'gc> ScriptObject<'gc> {
+    pub fn new(mc: MutationContext<'gc, '_>) -> Self {
+        Self(GcCell::allocate(
+            mc,
+            ScriptObjectData {
+                _marker: std::marker::PhantomData,
+            },
+        ))
+    }
+
+    pub fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    /// Get a weak handle to this object's own `GcCell`.
+    pub fn downgrade(&self) -> WeakScriptObject<'gc> {
+        WeakScriptObject(GcCell::downgrade(self.0))
+    }
+}
+
+impl<'gc> TObject<'gc> for ScriptObject<'gc> {
+    fn as_ptr(&self) -> *const ObjectPtr {
+        ScriptObject::as_ptr(self)
+    }
+}
+
+impl<'gc> std::fmt::Debug for ScriptObject<'gc> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ScriptObject").finish()
+    }
+}
+
+/// A weak handle to a `ScriptObject`, obtained via `ScriptObject::downgrade`.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct WeakScriptObject<'gc>(GcWeakCell<'gc, ScriptObjectData<'gc>>);
+
+impl<'gc> WeakScriptObject<'gc> {
+    pub fn upgrade(&self, mc: MutationContext<'gc, '_>) -> Option<ScriptObject<'gc>> {
+        self.0.upgrade(mc).map(ScriptObject)
+    }
+}
@@ -0,0 +1,63 @@
+//! AVM2 object backing for anything whose class extends
+//! `flash.display.DisplayObject` (e.g. `Video`).
+
+use crate::avm2::object::{Object, ObjectPtr, TObject};
+use crate::display_object::DisplayObject;
+use gc_arena::{Collect, GcCell, GcWeakCell, MutationContext};
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+struct StageObjectData<'gc> {
+    display_object: DisplayObject<'gc>,
+}
+
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct StageObject<'gc>(GcCell<'gc, StageObjectData<'gc>>);
+
+impl<'gc> StageObject<'gc> {
+    /// Wrap `display_object` in a `StageObject`, and point it back at the
+    /// resulting `Object` via `DisplayObject::set_object` - this is the
+    /// only place that back-link is ever set, so every `StageObject`'s
+    /// display object has one by construction.
+    pub fn for_display_object(mc: MutationContext<'gc, '_>, display_object: DisplayObject<'gc>) -> Self {
+        let stage_object = Self(GcCell::allocate(mc, StageObjectData { display_object }));
+        display_object.set_object(mc, Object::Stage(stage_object));
+        stage_object
+    }
+
+    pub fn display_object(&self) -> DisplayObject<'gc> {
+        self.0.read().display_object
+    }
+
+    pub fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    pub fn downgrade(&self) -> WeakStageObject<'gc> {
+        WeakStageObject(GcCell::downgrade(self.0))
+    }
+}
+
+impl<'gc> TObject<'gc> for StageObject<'gc> {
+    fn as_ptr(&self) -> *const ObjectPtr {
+        StageObject::as_ptr(self)
+    }
+}
+
+impl<'gc> std::fmt::Debug for StageObject<'gc> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("StageObject").finish()
+    }
+}
+
+/// A weak handle to a `StageObject`, obtained via `StageObject::downgrade`.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct WeakStageObject<'gc>(GcWeakCell<'gc, StageObjectData<'gc>>);
+
+impl<'gc> WeakStageObject<'gc> {
+    pub fn upgrade(&self, mc: MutationContext<'gc, '_>) -> Option<StageObject<'gc>> {
+        self.0.upgrade(mc).map(StageObject)
+    }
+}
@@ -0,0 +1,10 @@
+//! Minimal renderer-backend hook display objects paint themselves into.
+
+/// A target a display object can paint its own (non-child) contents into.
+pub trait RenderBackend {
+    /// Paint a solid-colored rectangle, in the renderer's local pixel
+    /// space. Used as a placeholder by display objects, like `Video`
+    /// with no decoded frame yet, that don't have real bitmap content to
+    /// hand off.
+    fn draw_rect(&mut self, bounds: (f64, f64, f64, f64), color: (u8, u8, u8, u8));
+}
@@ -0,0 +1,74 @@
+//! Display list objects.
+//!
+//! Like `avm2::object`, this only models the slice of the real display
+//! object system touched by `Video`: a single-variant enum standing in
+//! for the real `DisplayObject`, which in the full tree also covers
+//! `MovieClip`, `Bitmap`, text fields, and so on.
+
+pub mod video;
+
+pub use video::Video;
+
+use crate::avm2::Object as Avm2Object;
+use crate::backend::render::RenderBackend;
+use gc_arena::{Collect, MutationContext};
+
+/// A node in the display list.
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub enum DisplayObject<'gc> {
+    Video(Video<'gc>),
+}
+
+impl<'gc> From<Video<'gc>> for DisplayObject<'gc> {
+    fn from(video: Video<'gc>) -> Self {
+        DisplayObject::Video(video)
+    }
+}
+
+impl<'gc> DisplayObject<'gc> {
+    /// Get this display object's `Video` data, if it is one.
+    pub fn as_video(&self) -> Option<Video<'gc>> {
+        match self {
+            DisplayObject::Video(video) => Some(*video),
+        }
+    }
+
+    /// The parent of this display object in the display list, if any.
+    pub fn parent(&self) -> Option<DisplayObject<'gc>> {
+        match self {
+            DisplayObject::Video(video) => video.parent(),
+        }
+    }
+
+    /// The AVM2 object this display object backs, if it's been set. This
+    /// is the reverse of `StageObject::display_object()`.
+    pub fn object(&self) -> Option<Avm2Object<'gc>> {
+        match self {
+            DisplayObject::Video(video) => video.object(),
+        }
+    }
+
+    /// Set the AVM2 object this display object backs.
+    pub fn set_object(&self, mc: MutationContext<'gc, '_>, object: Avm2Object<'gc>) {
+        match self {
+            DisplayObject::Video(video) => video.set_object(mc, object),
+        }
+    }
+
+    /// This display object's bounds in its own local coordinate space,
+    /// ignoring children.
+    pub fn self_bounds(&self) -> (f64, f64, f64, f64) {
+        match self {
+            DisplayObject::Video(video) => video.self_bounds(),
+        }
+    }
+
+    /// Paint this display object's own contents (not its children) into
+    /// the renderer.
+    pub fn render_self(&self, renderer: &mut dyn RenderBackend) {
+        match self {
+            DisplayObject::Video(video) => video.render_self(renderer),
+        }
+    }
+}
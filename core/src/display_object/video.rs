@@ -0,0 +1,210 @@
+//! `Video` display object, used to present frames decoded from an attached
+//! `NetStream` or `Camera` within the display list.
+
+use crate::avm2::Object as Avm2Object;
+use crate::backend::render::RenderBackend;
+use crate::display_object::DisplayObject;
+use gc_arena::{Collect, GcCell, MutationContext};
+
+/// The default size of a `Video` object when no `width`/`height` are
+/// given to its constructor.
+pub const DEFAULT_VIDEO_SIZE: (u32, u32) = (320, 240);
+
+/// The source of frames currently being fed into a `Video` object.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+enum VideoSource<'gc> {
+    /// No media is attached; the video renders nothing.
+    None,
+
+    /// Frames are being supplied by a `NetStream`.
+    NetStream(Avm2Object<'gc>),
+
+    /// Frames are being supplied by a `Camera`.
+    Camera(Avm2Object<'gc>),
+}
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+struct VideoData<'gc> {
+    parent: Option<DisplayObject<'gc>>,
+
+    /// The AVM2 object this display object backs, set once by
+    /// `StageObject::for_display_object` right after construction. This
+    /// is the reverse of `StageObject::display_object()`, and lets
+    /// display-list code (e.g. the capture/bubble ancestor walk in
+    /// `avm2::events`) get back from a parent `DisplayObject` to the
+    /// `Object` its listeners are registered on.
+    object: Option<Avm2Object<'gc>>,
+
+    source: VideoSource<'gc>,
+
+    /// The width and height of the video's bounds, in pixels. These are
+    /// set from the constructor's `width`/`height` arguments and are not
+    /// changed by attaching media - Flash does not resize a `Video` to
+    /// match its source.
+    width: u32,
+    height: u32,
+
+    smoothing: bool,
+
+    /// Deblocking strength: `0` defers to the media's own setting, `1`
+    /// disables deblocking, and `2`-`6` request increasing filter
+    /// strength, matching the `deblocking` property on the reference
+    /// player.
+    deblocking: i32,
+}
+
+/// The display-object side of a `flash.media.Video` instance.
+///
+/// This holds the attached media source and the video's bounds. No
+/// frames are decoded or drawn: while a `NetStream`/`Camera` is attached,
+/// `render_self` paints a solid black rectangle over this object's
+/// bounds, and with nothing attached it paints nothing.
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct Video<'gc>(GcCell<'gc, VideoData<'gc>>);
+
+impl<'gc> Video<'gc> {
+    pub fn new(gc_context: MutationContext<'gc, '_>, width: u32, height: u32) -> Self {
+        Self(GcCell::allocate(
+            gc_context,
+            VideoData {
+                parent: None,
+                object: None,
+                source: VideoSource::None,
+                width,
+                height,
+                smoothing: false,
+                deblocking: 0,
+            },
+        ))
+    }
+
+    pub fn parent(self) -> Option<DisplayObject<'gc>> {
+        self.0.read().parent
+    }
+
+    /// The AVM2 object this display object backs, if it's been set.
+    pub fn object(self) -> Option<Avm2Object<'gc>> {
+        self.0.read().object
+    }
+
+    /// Set the AVM2 object this display object backs. Called once, by
+    /// `StageObject::for_display_object`.
+    pub fn set_object(self, mc: MutationContext<'gc, '_>, object: Avm2Object<'gc>) {
+        self.0.write(mc).object = Some(object);
+    }
+
+    /// Set this video's parent in the display list.
+    ///
+    /// Known limitation: nothing in this tree actually adds a `Video` to
+    /// a parent's child list yet (there is no `addChild`/display-list
+    /// container wiring here), so `parent()` is always `None` in
+    /// practice and the capture/bubble ancestor walk in `avm2::events`
+    /// never finds any ancestors for a bare `Video`. This setter exists
+    /// so that wiring, once it lands, has something to call.
+    pub fn set_parent(self, mc: MutationContext<'gc, '_>, parent: Option<DisplayObject<'gc>>) {
+        self.0.write(mc).parent = parent;
+    }
+
+    pub fn width(self) -> u32 {
+        self.0.read().width
+    }
+
+    pub fn height(self) -> u32 {
+        self.0.read().height
+    }
+
+    pub fn self_bounds(self) -> (f64, f64, f64, f64) {
+        let read = self.0.read();
+        (0.0, 0.0, read.width as f64, read.height as f64)
+    }
+
+    pub fn set_dimensions(self, mc: MutationContext<'gc, '_>, width: u32, height: u32) {
+        let mut write = self.0.write(mc);
+        write.width = width;
+        write.height = height;
+    }
+
+    pub fn smoothing(self) -> bool {
+        self.0.read().smoothing
+    }
+
+    pub fn set_smoothing(self, mc: MutationContext<'gc, '_>, value: bool) {
+        self.0.write(mc).smoothing = value;
+    }
+
+    pub fn deblocking(self) -> i32 {
+        self.0.read().deblocking
+    }
+
+    pub fn set_deblocking(self, mc: MutationContext<'gc, '_>, value: i32) {
+        self.0.write(mc).deblocking = value;
+    }
+
+    /// Attach a `NetStream` as this video's frame source, replacing
+    /// whatever was previously attached.
+    pub fn attach_net_stream(self, mc: MutationContext<'gc, '_>, ns: Avm2Object<'gc>) {
+        self.0.write(mc).source = VideoSource::NetStream(ns);
+    }
+
+    /// Attach a `Camera` as this video's frame source, replacing whatever
+    /// was previously attached.
+    pub fn attach_camera(self, mc: MutationContext<'gc, '_>, cam: Avm2Object<'gc>) {
+        self.0.write(mc).source = VideoSource::Camera(cam);
+    }
+
+    /// Detach any attached media, going back to rendering nothing.
+    pub fn clear(self, mc: MutationContext<'gc, '_>) {
+        self.0.write(mc).source = VideoSource::None;
+    }
+
+    /// Paint this video's placeholder into its bounds.
+    ///
+    /// No decoded frame is drawn: a `Video` with a source attached paints
+    /// its bounds solid black so its place in the display list is
+    /// visible, and a `Video` with no source attached paints nothing.
+    pub fn render_self(self, renderer: &mut dyn RenderBackend) {
+        let has_source = !matches!(self.0.read().source, VideoSource::None);
+
+        if has_source {
+            renderer.draw_rect(self.self_bounds(), (0, 0, 0, 255));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct RecordingRenderer {
+        rects: Vec<(f64, f64, f64, f64)>,
+    }
+
+    impl RenderBackend for RecordingRenderer {
+        fn draw_rect(&mut self, bounds: (f64, f64, f64, f64), _color: (u8, u8, u8, u8)) {
+            self.rects.push(bounds);
+        }
+    }
+
+    #[test]
+    fn self_bounds_match_constructor_dimensions() {
+        gc_arena::rootless_arena(|mc| {
+            let video = Video::new(mc, 640, 480);
+            assert_eq!(video.self_bounds(), (0.0, 0.0, 640.0, 480.0));
+        });
+    }
+
+    #[test]
+    fn renders_nothing_without_an_attached_source() {
+        gc_arena::rootless_arena(|mc| {
+            let video = Video::new(mc, DEFAULT_VIDEO_SIZE.0, DEFAULT_VIDEO_SIZE.1);
+            let mut renderer = RecordingRenderer { rects: Vec::new() };
+
+            video.render_self(&mut renderer);
+
+            assert!(renderer.rects.is_empty());
+        });
+    }
+}